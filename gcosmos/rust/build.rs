@@ -1,5 +1,16 @@
+use std::path::PathBuf;
 use tonic_build;
 
+// NOTE: this crate has no checked-in Cargo.toml in this tree yet. Besides
+// `tonic`/`tonic-build`/`tokio`/`prost`, the manifest that eventually gets
+// added here needs to declare:
+//   - tonic-health   (grpc.health.v1 health service)
+//   - tonic-reflection (server reflection / FileDescriptorSet registration)
+//   - tokio-stream   (ReceiverStream for WatchBlocksWatermark)
+//   - thiserror      (GordianClientError)
+//   - uuid           (feature = "v4", for TokenValidator::issue)
+// Verify these are present before merging; none of the cargo build/clippy/
+// test gates can run against this tree until they are.
 fn main() {
     // tonic_build::compile_protos("proto/server/grpc.proto")
     //     .unwrap_or_else(|e| panic!("Failed to compile protos {:?}", e));
@@ -15,9 +26,14 @@ fn main() {
     //     .compile(&[proto_file], &["."])
     //     .unwrap_or_else(|e| panic!("protobuf compile error: {}", e));
 
+    // Emitted alongside the generated code so the server can register it
+    // with tonic_reflection and be introspected with grpcurl.
+    let descriptor_path = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("gordian_descriptor.bin");
+
     tonic_build::configure()
-        .build_server(false)
+        .build_server(true)
         .build_client(true)
+        .file_descriptor_set_path(&descriptor_path)
         .out_dir("src") // Specify the output directory for generated Rust files
         .compile(
             &["../proto/server/grpc.proto"], // Paths to your .proto files
@@ -0,0 +1,59 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Tracks the engine's current committed and voting block heights.
+///
+/// This is the shared state the gRPC service reads from; the consensus
+/// engine advances it as it commits blocks and moves the voting round
+/// forward.
+#[derive(Debug)]
+pub struct BlockWatermarkStore {
+    committed_height: AtomicU64,
+    voting_height: AtomicU64,
+    last_advanced: Mutex<Instant>,
+}
+
+impl Default for BlockWatermarkStore {
+    fn default() -> Self {
+        Self {
+            committed_height: AtomicU64::new(0),
+            voting_height: AtomicU64::new(0),
+            last_advanced: Mutex::new(Instant::now()),
+        }
+    }
+}
+
+impl BlockWatermarkStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn watermark(&self) -> (u64, u64) {
+        (
+            self.committed_height.load(Ordering::SeqCst),
+            self.voting_height.load(Ordering::SeqCst),
+        )
+    }
+
+    pub fn set_committed_height(&self, height: u64) {
+        if self.committed_height.swap(height, Ordering::SeqCst) != height {
+            self.mark_advanced();
+        }
+    }
+
+    pub fn set_voting_height(&self, height: u64) {
+        if self.voting_height.swap(height, Ordering::SeqCst) != height {
+            self.mark_advanced();
+        }
+    }
+
+    /// How long it's been since the committed or voting height last changed.
+    pub fn since_last_advance(&self) -> std::time::Duration {
+        self.last_advanced.lock().unwrap().elapsed()
+    }
+
+    fn mark_advanced(&self) {
+        *self.last_advanced.lock().unwrap() = Instant::now();
+    }
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use tonic::transport::{Channel, Endpoint};
+
+use crate::server::gordian_grpc_client::GordianGrpcClient;
+
+/// Exponential backoff used while (re)connecting to a Gordian node.
+#[derive(Debug, Clone)]
+pub struct BackoffConfig {
+    pub initial_interval: Duration,
+    pub max_interval: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_millis(200),
+            max_interval: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+/// Configuration for `GordianClient`: where to connect, how long to wait,
+/// and how to recover when the node goes away (e.g. during an upgrade).
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub endpoint: String,
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    pub backoff: BackoffConfig,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://127.0.0.1:9092".to_string(),
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(10),
+            backoff: BackoffConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GordianClientError {
+    #[error("invalid endpoint: {0}")]
+    InvalidEndpoint(#[from] tonic::transport::Error),
+    #[error("invalid access token: {0}")]
+    InvalidToken(tonic::Status),
+}
+
+/// A `GordianGrpcClient` wrapper that owns connection setup: configurable
+/// timeouts and exponential-backoff (re)connect, instead of a hard-coded
+/// endpoint and `.unwrap()`s. `new` retries until the node is reachable and
+/// `reconnect` re-establishes the channel with the same policy; calls made
+/// through `client()` are not retried or reconnected automatically.
+pub struct GordianClient {
+    config: ClientConfig,
+    channel: Channel,
+}
+
+impl GordianClient {
+    /// Connects to `config.endpoint`, retrying with exponential backoff on
+    /// transport errors rather than failing the first time the node isn't
+    /// reachable yet.
+    pub async fn new(config: ClientConfig) -> Result<Self, GordianClientError> {
+        let channel = Self::connect_with_backoff(&config).await?;
+        Ok(Self { config, channel })
+    }
+
+    async fn connect_with_backoff(config: &ClientConfig) -> Result<Channel, GordianClientError> {
+        let endpoint = Endpoint::from_shared(config.endpoint.clone())?
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout);
+
+        let mut backoff = config.backoff.initial_interval;
+        loop {
+            match endpoint.connect().await {
+                Ok(channel) => return Ok(channel),
+                Err(e) => {
+                    eprintln!(
+                        "failed to connect to {}: {e}, retrying in {:?}",
+                        config.endpoint, backoff
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff
+                        .mul_f64(config.backoff.multiplier)
+                        .min(config.backoff.max_interval);
+                }
+            }
+        }
+    }
+
+    /// Drops the current channel and reconnects with the same backoff
+    /// policy. Callers should invoke this after observing a transport
+    /// error from a call made against `client()`.
+    pub async fn reconnect(&mut self) -> Result<(), GordianClientError> {
+        self.channel = Self::connect_with_backoff(&self.config).await?;
+        Ok(())
+    }
+
+    /// Returns a `GordianGrpcClient` bound to the current channel.
+    pub fn client(&self) -> GordianGrpcClient<Channel> {
+        GordianGrpcClient::new(self.channel.clone())
+    }
+
+    /// The endpoint this client is (re)connecting to.
+    pub fn endpoint(&self) -> &str {
+        &self.config.endpoint
+    }
+}
@@ -0,0 +1,147 @@
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::Stream;
+use tonic::{transport::Server, Request, Response, Status};
+
+use crate::auth::TokenValidator;
+use crate::block_store::BlockWatermarkStore;
+use crate::health;
+use crate::server::gordian_grpc_server::{GordianGrpc, GordianGrpcServer};
+use crate::server::{
+    CurrentBlockRequest, CurrentBlockResponse, SigninRequest, SigninResponse,
+};
+
+/// How often `watch_blocks_watermark` checks the store for an advance.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Encoded `FileDescriptorSet` emitted by `build.rs`, registered with
+/// tonic_reflection so tools like `grpcurl` can list and invoke
+/// `GordianGrpc` methods without the `.proto` on hand.
+const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/gordian_descriptor.bin"));
+
+/// Reference implementation of `GordianGrpc`, backed by a
+/// `BlockWatermarkStore` shared with the consensus engine.
+pub struct GordianGrpcService {
+    store: Arc<BlockWatermarkStore>,
+    tokens: TokenValidator,
+}
+
+impl GordianGrpcService {
+    pub fn new(store: Arc<BlockWatermarkStore>, tokens: TokenValidator) -> Self {
+        Self { store, tokens }
+    }
+
+    fn watermark_response(&self) -> CurrentBlockResponse {
+        let (committed_height, voting_height) = self.store.watermark();
+        CurrentBlockResponse {
+            committed_height,
+            voting_height,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl GordianGrpc for GordianGrpcService {
+    async fn get_blocks_watermark(
+        &self,
+        request: Request<CurrentBlockRequest>,
+    ) -> Result<Response<CurrentBlockResponse>, Status> {
+        self.tokens.validate(&request)?;
+        Ok(Response::new(self.watermark_response()))
+    }
+
+    type WatchBlocksWatermarkStream =
+        Pin<Box<dyn Stream<Item = Result<CurrentBlockResponse, Status>> + Send + 'static>>;
+
+    async fn watch_blocks_watermark(
+        &self,
+        request: Request<CurrentBlockRequest>,
+    ) -> Result<Response<Self::WatchBlocksWatermarkStream>, Status> {
+        self.tokens.validate(&request)?;
+        let store = self.store.clone();
+        let (tx, rx) = tokio::sync::mpsc::channel(16);
+
+        tokio::spawn(async move {
+            let mut last = store.watermark();
+            if tx.send(Ok(CurrentBlockResponse {
+                committed_height: last.0,
+                voting_height: last.1,
+            }))
+            .await
+            .is_err()
+            {
+                return;
+            }
+
+            loop {
+                tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+                let current = store.watermark();
+                if current != last {
+                    last = current;
+                    if tx.send(Ok(CurrentBlockResponse {
+                        committed_height: current.0,
+                        voting_height: current.1,
+                    }))
+                    .await
+                    .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Ok(Response::new(
+            Box::pin(ReceiverStream::new(rx)) as Self::WatchBlocksWatermarkStream
+        ))
+    }
+
+    async fn signin(
+        &self,
+        request: Request<SigninRequest>,
+    ) -> Result<Response<SigninResponse>, Status> {
+        let req = request.into_inner();
+
+        // Reference credential check; real deployments should plug in
+        // their own user store here.
+        if req.username != "operator" || req.password != "operator" {
+            return Err(Status::unauthenticated("invalid username or password"));
+        }
+
+        Ok(Response::new(SigninResponse {
+            access_token: self.tokens.issue(),
+        }))
+    }
+}
+
+/// Binds and serves `GordianGrpc` on `addr` until the process is killed.
+/// `staleness_threshold` is how long the watermark can go without
+/// advancing before the health service reports `NOT_SERVING`.
+pub async fn serve(
+    addr: SocketAddr,
+    store: Arc<BlockWatermarkStore>,
+    staleness_threshold: Duration,
+) -> Result<(), tonic::transport::Error> {
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    health::spawn_watermark_health_task(store.clone(), health_reporter, staleness_threshold);
+
+    let reflection_service = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .build()
+        .unwrap_or_else(|e| panic!("failed to build reflection service: {:?}", e));
+
+    let service = GordianGrpcService::new(store, TokenValidator::new());
+
+    println!("gordian grpc server listening on {addr}");
+
+    Server::builder()
+        .add_service(GordianGrpcServer::new(service))
+        .add_service(health_service)
+        .add_service(reflection_service)
+        .serve(addr)
+        .await
+}
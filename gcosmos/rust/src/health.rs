@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tonic_health::server::HealthReporter;
+use tonic_health::ServingStatus;
+
+use crate::block_store::BlockWatermarkStore;
+
+/// Name `GordianGrpc` registers itself under in the health service, so
+/// `grpcurl -plaintext <addr> grpc.health.v1.Health/Check` and k8s probes
+/// can target it specifically rather than the overall server.
+pub const GORDIAN_GRPC_SERVICE_NAME: &str = "server.GordianGrpc";
+
+/// Default for `staleness_threshold` when an operator doesn't override it.
+pub const DEFAULT_STALENESS_THRESHOLD: Duration = Duration::from_secs(10);
+
+/// How often the watermark is checked against the staleness threshold.
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Spawns a task that keeps `GordianGrpc`'s health status in sync with
+/// whether the engine is still producing blocks: `SERVING` while the
+/// watermark keeps advancing, `NOT_SERVING` once it's gone more than
+/// `staleness_threshold` without an update.
+///
+/// Mirrors the approach Agones took when it moved to tonic: a dedicated,
+/// continuously-running health task rather than a one-shot status set at
+/// startup.
+pub fn spawn_watermark_health_task(
+    store: Arc<BlockWatermarkStore>,
+    mut reporter: HealthReporter,
+    staleness_threshold: Duration,
+) {
+    tokio::spawn(async move {
+        reporter
+            .set_service_status(GORDIAN_GRPC_SERVICE_NAME, ServingStatus::Serving)
+            .await;
+
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let status = if store.since_last_advance() > staleness_threshold {
+                ServingStatus::NotServing
+            } else {
+                ServingStatus::Serving
+            };
+            reporter
+                .set_service_status(GORDIAN_GRPC_SERVICE_NAME, status)
+                .await;
+        }
+    });
+}
@@ -1,14 +1,147 @@
+mod auth;
+mod block_store;
+mod client;
+mod grpc_server;
+mod health;
 mod server;
-use tokio;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use block_store::BlockWatermarkStore;
+use client::{ClientConfig, GordianClient};
+
+/// Default bind address when neither `--addr` nor `GORDIAN_GRPC_ADDR` is
+/// set.
+const DEFAULT_GRPC_ADDR: &str = "127.0.0.1:9092";
 
 #[tokio::main]
 async fn main() {
+    let mut args = std::env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("server") => run_server(args).await,
+        Some("--health") => run_health_check().await,
+        _ => run_client().await,
+    }
+}
+
+/// Runs the reference `GordianGrpc` server. The bind address defaults to
+/// `127.0.0.1:9092` but can be overridden with `--addr <SocketAddr>` or
+/// the `GORDIAN_GRPC_ADDR` environment variable, so operators aren't
+/// stuck with a hardcoded port.
+async fn run_server(mut args: impl Iterator<Item = String>) {
+    let mut addr_arg = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => {
+                addr_arg = Some(
+                    args.next()
+                        .unwrap_or_else(|| panic!("--addr requires a value")),
+                );
+            }
+            other => panic!("unrecognized server argument: {other}"),
+        }
+    }
+
+    let addr_str = addr_arg
+        .or_else(|| std::env::var("GORDIAN_GRPC_ADDR").ok())
+        .unwrap_or_else(|| DEFAULT_GRPC_ADDR.to_string());
+    let addr: SocketAddr = addr_str
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid server address {addr_str:?}: {e}"));
+
+    let staleness_threshold = std::env::var("GORDIAN_STALENESS_THRESHOLD_SECS")
+        .ok()
+        .map(|s| {
+            let secs: u64 = s
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid GORDIAN_STALENESS_THRESHOLD_SECS {s:?}: {e}"));
+            std::time::Duration::from_secs(secs)
+        })
+        .unwrap_or(health::DEFAULT_STALENESS_THRESHOLD);
+
+    let store = Arc::new(BlockWatermarkStore::new());
+
+    grpc_server::serve(addr, store, staleness_threshold)
+        .await
+        .unwrap_or_else(|e| panic!("gordian grpc server failed: {:?}", e));
+}
+
+/// Queries the standard `grpc.health.v1.Health` service for the status of
+/// `GordianGrpc` and prints it, exiting non-zero if it isn't `SERVING`.
+async fn run_health_check() {
+    use tonic_health::pb::health_client::HealthClient;
+    use tonic_health::pb::HealthCheckRequest;
+
+    let conn = GordianClient::new(ClientConfig::default())
+        .await
+        .unwrap_or_else(|e| panic!("failed to connect: {e}"));
+    let mut client = HealthClient::new(conn.client().into_inner());
+
+    let resp = client
+        .check(HealthCheckRequest {
+            service: health::GORDIAN_GRPC_SERVICE_NAME.to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+
+    println!("status={:?}", resp.status());
+    if resp.status() != tonic_health::pb::health_check_response::ServingStatus::Serving {
+        std::process::exit(1);
+    }
+}
+
+/// Runs the client demo: signs in, subscribes to the watermark stream and
+/// prints every update as it arrives. A dropped connection (e.g. the node
+/// restarting during an upgrade) is not fatal: it reconnects with
+/// exponential backoff and resumes watching instead of panicking.
+async fn run_client() {
     println!("Clients!");
 
-    let conn = &mut server::gordian_grpc_client::GordianGrpcClient::connect("http://127.0.0.1:9092").await.unwrap();
+    let mut conn = GordianClient::new(ClientConfig::default())
+        .await
+        .unwrap_or_else(|e| panic!("failed to connect: {e}"));
+
+    loop {
+        if let Err(status) = watch_session(&mut conn).await {
+            eprintln!("lost connection to {}: {status}, reconnecting...", conn.endpoint());
+            conn.reconnect()
+                .await
+                .unwrap_or_else(|e| panic!("failed to reconnect: {e}"));
+        }
+    }
+}
+
+/// Signs in, opens the watermark stream over `conn` and prints every
+/// update until the stream ends or a transport error is hit.
+async fn watch_session(conn: &mut GordianClient) -> Result<(), tonic::Status> {
+    let signin = conn
+        .client()
+        .signin(server::SigninRequest {
+            username: "operator".to_string(),
+            password: "operator".to_string(),
+        })
+        .await?
+        .into_inner();
+
+    let authed = &mut server::gordian_grpc_client::GordianGrpcClient::connect_with_token(
+        conn.endpoint().to_string(),
+        &signin.access_token,
+    )
+    .await
+    .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
 
     let req = server::CurrentBlockRequest{};
 
-    let resp = conn.get_blocks_watermark(req).await.unwrap();
-    println!("RESPONSE={:#?}", resp);
-}
\ No newline at end of file
+    // Subscribe instead of polling: the server pushes the current watermark,
+    // then a new message whenever the committed/voting watermark changes.
+    let mut stream = authed.watch_blocks_watermark(req).await?.into_inner();
+
+    while let Some(resp) = stream.message().await? {
+        println!("RESPONSE={:#?}", resp);
+    }
+
+    Ok(())
+}